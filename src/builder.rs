@@ -0,0 +1,109 @@
+//! Manual, field-by-field construction of a `UUID`, for interop with
+//! formats such as the Windows `GUID`.
+
+use crate::{Bytes, Layout, Variant, Version, UUID};
+
+/// Builds a `Layout` byte by byte, letting the version and variant be set
+/// explicitly instead of being inferred through the [`layout!`] macro.
+///
+/// [`layout!`]: crate::layout
+pub struct Builder(Bytes);
+
+impl Builder {
+    /// Creates a `Builder` from the raw 16 bytes of a `UUID`.
+    pub const fn from_bytes(bytes: Bytes) -> Self {
+        Builder(bytes)
+    }
+
+    /// Sets the four version bits in the high nibble of byte 6.
+    pub fn with_version(mut self, version: Version) -> Self {
+        self.0[6] = (self.0[6] & 0x0F) | (version as u8) << 4;
+        self
+    }
+
+    /// Sets the variant bits in the high nibble of byte 8.
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.0[8] = (self.0[8] & 0x0F) | (variant as u8) << 4;
+        self
+    }
+
+    /// Builds the final `Layout`.
+    pub fn build(self) -> Layout {
+        Layout::from_bytes(self.0)
+    }
+}
+
+impl UUID {
+    /// Creates `UUID` from the big-endian `Data1`/`Data2`/`Data3`/`Data4`
+    /// fields of a Windows `GUID`.
+    pub fn from_fields(d1: u32, d2: u16, d3: u16, d4: &[u8; 8]) -> Layout {
+        let bytes = assemble_fields(d1.to_be_bytes(), d2.to_be_bytes(), d3.to_be_bytes(), d4);
+        Layout::from_bytes(bytes)
+    }
+
+    /// Returns the big-endian `Data1`/`Data2`/`Data3`/`Data4` fields of `self`.
+    pub fn as_fields(&self) -> (u32, u16, u16, [u8; 8]) {
+        disassemble_fields(self.as_bytes(), u32::from_be_bytes, u16::from_be_bytes)
+    }
+
+    /// Creates `UUID` from the little-endian `Data1`/`Data2`/`Data3`/`Data4`
+    /// fields of a Windows `GUID`.
+    pub fn from_fields_le(d1: u32, d2: u16, d3: u16, d4: &[u8; 8]) -> Layout {
+        let bytes = assemble_fields(d1.to_le_bytes(), d2.to_le_bytes(), d3.to_le_bytes(), d4);
+        Layout::from_bytes(bytes)
+    }
+
+    /// Returns the little-endian `Data1`/`Data2`/`Data3`/`Data4` fields of `self`.
+    pub fn to_fields_le(&self) -> (u32, u16, u16, [u8; 8]) {
+        disassemble_fields(self.as_bytes(), u32::from_le_bytes, u16::from_le_bytes)
+    }
+}
+
+fn assemble_fields(d1: [u8; 4], d2: [u8; 2], d3: [u8; 2], d4: &[u8; 8]) -> Bytes {
+    [
+        d1[0], d1[1], d1[2], d1[3], d2[0], d2[1], d3[0], d3[1], d4[0], d4[1], d4[2], d4[3], d4[4],
+        d4[5], d4[6], d4[7],
+    ]
+}
+
+fn disassemble_fields(
+    bytes: Bytes,
+    read_u32: fn([u8; 4]) -> u32,
+    read_u16: fn([u8; 2]) -> u16,
+) -> (u32, u16, u16, [u8; 8]) {
+    let d1 = read_u32([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let d2 = read_u16([bytes[4], bytes[5]]);
+    let d3 = read_u16([bytes[6], bytes[7]]);
+
+    let mut d4 = [0u8; 8];
+    d4.copy_from_slice(&bytes[8..16]);
+
+    (d1, d2, d3, d4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_sets_version_and_variant() {
+        let layout = Builder::from_bytes([0; 16])
+            .with_version(Version::RAND)
+            .with_variant(Variant::RFC4122)
+            .build();
+
+        assert_eq!(layout.get_version(), Ok(Version::RAND));
+        assert_eq!(layout.get_variant(), Ok(Variant::RFC4122));
+    }
+
+    #[test]
+    fn fields_round_trip() {
+        let uuid = UUID::NAMESPACE_DNS;
+
+        let (d1, d2, d3, d4) = uuid.as_fields();
+        assert_eq!(UUID::from_fields(d1, d2, d3, &d4).generate(), uuid);
+
+        let (d1, d2, d3, d4) = uuid.to_fields_le();
+        assert_eq!(UUID::from_fields_le(d1, d2, d3, &d4).generate(), uuid);
+    }
+}
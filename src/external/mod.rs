@@ -0,0 +1,2 @@
+#[cfg(feature = "serde")]
+pub mod serde_support;
@@ -0,0 +1,79 @@
+//! `serde` support for `UUID`, gated behind the `serde` Cargo feature.
+
+use core::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{Layout, UUID};
+
+impl Serialize for UUID {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.hyphenated().to_string())
+        } else {
+            serializer.serialize_bytes(&self.as_bytes())
+        }
+    }
+}
+
+struct UuidVisitor;
+
+impl<'de> Visitor<'de> for UuidVisitor {
+    type Value = UUID;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a UUID string or 16 bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<UUID, E> {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<UUID, E> {
+        let bytes: [u8; 16] = value
+            .try_into()
+            .map_err(|_| E::invalid_length(value.len(), &"16 bytes"))?;
+
+        Ok(Layout::from_bytes(bytes).generate())
+    }
+}
+
+impl<'de> Deserialize<'de> for UUID {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(UuidVisitor)
+        } else {
+            deserializer.deserialize_bytes(UuidVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_human_readable() {
+        let uuid = UUID::v3("test", UUID::NAMESPACE_DNS).generate();
+        let json = serde_json::to_string(&uuid).unwrap();
+
+        assert_eq!(serde_json::from_str::<UUID>(&json).unwrap(), uuid);
+    }
+
+    #[test]
+    fn round_trip_human_readable_non_rfc4122() {
+        let uuid = UUID::nil();
+        let json = serde_json::to_string(&uuid).unwrap();
+
+        assert_eq!(serde_json::from_str::<UUID>(&json).unwrap(), uuid);
+    }
+
+    #[test]
+    fn round_trip_binary() {
+        let uuid = UUID::NAMESPACE_DNS;
+        let bytes = bincode::serialize(&uuid).unwrap();
+
+        assert_eq!(bincode::deserialize::<UUID>(&bytes).unwrap(), uuid);
+    }
+}
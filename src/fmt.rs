@@ -0,0 +1,236 @@
+//! Adapters for the textual representations of a `UUID`.
+
+use core::fmt;
+
+use crate::UUID;
+
+const HEX_LOWER: &[u8; 16] = b"0123456789abcdef";
+const HEX_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+/// An adapter for formatting a `UUID` as 32 contiguous hex digits, with no
+/// hyphens, e.g. `67e5504410b1426f9247bb680e5fe0c8`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Simple(UUID);
+
+/// An adapter for formatting a `UUID` in the hyphenated `8-4-4-4-12` form,
+/// e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Hyphenated(UUID);
+
+/// An adapter for formatting a `UUID` as a hyphenated string wrapped in
+/// braces, the Microsoft `GUID` form, e.g.
+/// `{67e55044-10b1-426f-9247-bb680e5fe0c8}`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Braced(UUID);
+
+/// An adapter for formatting a `UUID` as a `urn:uuid:` URN, e.g.
+/// `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Urn(UUID);
+
+/// Writes `src` as hex digits into `buffer`, optionally grouped with
+/// hyphens and wrapped in a prefix/suffix, returning the written `&str`.
+fn encode<'b>(
+    src: &[u8; 16],
+    buffer: &'b mut [u8],
+    hex: &[u8; 16],
+    hyphens: bool,
+    prefix: &[u8],
+    suffix: &[u8],
+) -> &'b str {
+    let mut idx = 0;
+
+    buffer[idx..idx + prefix.len()].copy_from_slice(prefix);
+    idx += prefix.len();
+
+    for (i, byte) in src.iter().enumerate() {
+        if hyphens && matches!(i, 4 | 6 | 8 | 10) {
+            buffer[idx] = b'-';
+            idx += 1;
+        }
+
+        buffer[idx] = hex[(byte >> 4) as usize];
+        buffer[idx + 1] = hex[(byte & 0xf) as usize];
+        idx += 2;
+    }
+
+    buffer[idx..idx + suffix.len()].copy_from_slice(suffix);
+    idx += suffix.len();
+
+    core::str::from_utf8(&buffer[..idx]).unwrap()
+}
+
+impl Simple {
+    /// The length of a simple-formatted `UUID` string.
+    pub const LENGTH: usize = 32;
+
+    pub const fn from_uuid(uuid: UUID) -> Self {
+        Simple(uuid)
+    }
+
+    /// Writes the lower-hex simple form of the `UUID` into `buffer`.
+    pub fn encode_lower<'b>(&self, buffer: &'b mut [u8]) -> &'b str {
+        encode(&self.0.as_bytes(), buffer, HEX_LOWER, false, b"", b"")
+    }
+
+    /// Writes the upper-hex simple form of the `UUID` into `buffer`.
+    pub fn encode_upper<'b>(&self, buffer: &'b mut [u8]) -> &'b str {
+        encode(&self.0.as_bytes(), buffer, HEX_UPPER, false, b"", b"")
+    }
+}
+
+impl Hyphenated {
+    /// The length of a hyphenated-formatted `UUID` string.
+    pub const LENGTH: usize = 36;
+
+    pub const fn from_uuid(uuid: UUID) -> Self {
+        Hyphenated(uuid)
+    }
+
+    /// Writes the lower-hex hyphenated form of the `UUID` into `buffer`.
+    pub fn encode_lower<'b>(&self, buffer: &'b mut [u8]) -> &'b str {
+        encode(&self.0.as_bytes(), buffer, HEX_LOWER, true, b"", b"")
+    }
+
+    /// Writes the upper-hex hyphenated form of the `UUID` into `buffer`.
+    pub fn encode_upper<'b>(&self, buffer: &'b mut [u8]) -> &'b str {
+        encode(&self.0.as_bytes(), buffer, HEX_UPPER, true, b"", b"")
+    }
+}
+
+impl Braced {
+    /// The length of a braced-formatted `UUID` string.
+    pub const LENGTH: usize = 38;
+
+    pub const fn from_uuid(uuid: UUID) -> Self {
+        Braced(uuid)
+    }
+
+    /// Writes the lower-hex braced form of the `UUID` into `buffer`.
+    pub fn encode_lower<'b>(&self, buffer: &'b mut [u8]) -> &'b str {
+        encode(&self.0.as_bytes(), buffer, HEX_LOWER, true, b"{", b"}")
+    }
+
+    /// Writes the upper-hex braced form of the `UUID` into `buffer`.
+    pub fn encode_upper<'b>(&self, buffer: &'b mut [u8]) -> &'b str {
+        encode(&self.0.as_bytes(), buffer, HEX_UPPER, true, b"{", b"}")
+    }
+}
+
+impl Urn {
+    /// The length of a URN-formatted `UUID` string.
+    pub const LENGTH: usize = 45;
+
+    pub const fn from_uuid(uuid: UUID) -> Self {
+        Urn(uuid)
+    }
+
+    /// Writes the lower-hex URN form of the `UUID` into `buffer`.
+    pub fn encode_lower<'b>(&self, buffer: &'b mut [u8]) -> &'b str {
+        encode(
+            &self.0.as_bytes(),
+            buffer,
+            HEX_LOWER,
+            true,
+            b"urn:uuid:",
+            b"",
+        )
+    }
+
+    /// Writes the upper-hex URN form of the `UUID` into `buffer`.
+    pub fn encode_upper<'b>(&self, buffer: &'b mut [u8]) -> &'b str {
+        encode(
+            &self.0.as_bytes(),
+            buffer,
+            HEX_UPPER,
+            true,
+            b"urn:uuid:",
+            b"",
+        )
+    }
+}
+
+macro_rules! impl_fmt_traits {
+    ($type:ident) => {
+        impl fmt::Display for $type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::LowerHex::fmt(self, f)
+            }
+        }
+
+        impl fmt::LowerHex for $type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.encode_lower(&mut [0; $type::LENGTH]))
+            }
+        }
+
+        impl fmt::UpperHex for $type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.encode_upper(&mut [0; $type::LENGTH]))
+            }
+        }
+    };
+}
+
+impl_fmt_traits!(Simple);
+impl_fmt_traits!(Hyphenated);
+impl_fmt_traits!(Braced);
+impl_fmt_traits!(Urn);
+
+impl UUID {
+    /// Returns an adapter that formats `self` as 32 contiguous hex digits.
+    pub const fn simple(&self) -> Simple {
+        Simple::from_uuid(*self)
+    }
+
+    /// Returns an adapter that formats `self` in the hyphenated
+    /// `8-4-4-4-12` form.
+    pub const fn hyphenated(&self) -> Hyphenated {
+        Hyphenated::from_uuid(*self)
+    }
+
+    /// Returns an adapter that formats `self` as a hyphenated string
+    /// wrapped in braces, the Microsoft `GUID` form.
+    pub const fn braced(&self) -> Braced {
+        Braced::from_uuid(*self)
+    }
+
+    /// Returns an adapter that formats `self` as a `urn:uuid:` URN.
+    pub const fn urn(&self) -> Urn {
+        Urn::from_uuid(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const NS: crate::UUID = crate::UUID::NAMESPACE_DNS;
+
+    #[test]
+    fn simple_form() {
+        assert_eq!(NS.simple().to_string(), "6ba7b8109dad11d180b400c04fd430c8");
+    }
+
+    #[test]
+    fn hyphenated_form() {
+        assert_eq!(
+            NS.hyphenated().to_string(),
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c8"
+        );
+    }
+
+    #[test]
+    fn braced_form() {
+        assert_eq!(
+            NS.braced().to_string(),
+            "{6ba7b810-9dad-11d1-80b4-00c04fd430c8}"
+        );
+    }
+
+    #[test]
+    fn urn_form() {
+        assert_eq!(
+            NS.urn().to_string(),
+            "urn:uuid:6ba7b810-9dad-11d1-80b4-00c04fd430c8"
+        );
+    }
+}
@@ -8,16 +8,31 @@
 #![doc(html_root_url = "https://docs.rs/unik")]
 #![feature(doc_cfg)]
 
+mod builder;
+#[cfg(feature = "serde")]
+mod external;
+pub mod fmt;
+mod parser;
 pub mod rfc4122;
 
-use core::fmt;
+pub use crate::builder::Builder;
+pub use crate::fmt::{Braced, Hyphenated, Simple, Urn};
+pub use crate::parser::Error;
+
+use core::fmt as core_fmt;
 use std::sync::atomic::{self, AtomicU16};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 pub use mac_address::{get_mac_address, MacAddress};
 
 /// Represent bytes of MAC address.
 pub type Node = MacAddress;
 
+/// Number of 100-ns ticks between the Gregorian calendar epoch
+/// (`1582-10-15 00:00:00`) and the Unix epoch, as used by `rfc4122`
+/// time-based UUIDs.
+const GREGORIAN_EPOCH_OFFSET: u64 = 0x01B2_1DD2_1381_4000;
+
 /// Is a 60-bit value. Represented by Coordinated Universal Time (UTC).
 ///
 /// NOTE: `TimeStamp` used as a `u64`. For this reason dates prior to gregorian
@@ -25,6 +40,22 @@ pub type Node = MacAddress;
 #[derive(Debug, Clone, Copy)]
 pub struct TimeStamp(pub u64);
 
+impl TimeStamp {
+    /// Returns the current time as a 60-bit count of 100-nanosecond intervals
+    /// since `1582-10-15 00:00:00` (UTC), as required by time-based UUIDs.
+    pub fn from_utc() -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system clock is set before the Unix epoch");
+
+        let ticks = now.as_secs() * 10_000_000
+            + u64::from(now.subsec_nanos()) / 100
+            + GREGORIAN_EPOCH_OFFSET;
+
+        TimeStamp(ticks & 0x0FFF_FFFF_FFFF_FFFF)
+    }
+}
+
 /// The simplified version of `UUID` in terms of fields that are integral numbers of octets.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct Layout {
@@ -93,6 +124,8 @@ impl Layout {
             0x3 => Ok(Version::MD5),
             0x4 => Ok(Version::RAND),
             0x5 => Ok(Version::SHA1),
+            0x6 => Ok(Version::V6),
+            0x7 => Ok(Version::V7),
             _ => Err("Invalid version"),
         }
     }
@@ -107,6 +140,46 @@ impl Layout {
             _ => Err("Invalid variant"),
         }
     }
+
+    /// Returns the IEEE-802 network address embedded in a time-based `UUID`.
+    pub const fn get_node(&self) -> Node {
+        self.node
+    }
+
+    /// Returns the timestamp embedded in a `TIME`, `DCE` or `V6` `UUID`,
+    /// or `None` for any other version.
+    pub const fn get_timestamp(&self) -> Option<TimeStamp> {
+        match self.get_version() {
+            Ok(Version::TIME) | Ok(Version::DCE) => {
+                let ticks = (self.field_high_and_version as u64 & 0x0FFF) << 48
+                    | (self.field_mid as u64) << 32
+                    | self.field_low as u64;
+
+                Some(TimeStamp(ticks))
+            }
+            Ok(Version::V6) => {
+                let ticks = (self.field_low as u64) << 28
+                    | (self.field_mid as u64) << 12
+                    | (self.field_high_and_version as u64 & 0x0FFF);
+
+                Some(TimeStamp(ticks))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the DCE domain embedded in a `DCE` `UUID`, or `None` for any
+    /// other version.
+    pub const fn get_domain(&self) -> Option<Domain> {
+        match self.get_version() {
+            Ok(Version::DCE) => match self.clock_seq_low {
+                0 => Some(Domain::PERSON),
+                1 => Some(Domain::GROUP),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
 }
 
 /// The `UUID` format is 16 octets.
@@ -122,6 +195,26 @@ impl UUID {
         self.0
     }
 
+    /// The nil `UUID`, as defined by `rfc4122`, with all 128 bits set to zero.
+    pub const fn nil() -> UUID {
+        UUID([0; 16])
+    }
+
+    /// The max `UUID`, as proposed beyond `rfc4122`, with all 128 bits set to one.
+    pub const fn max() -> UUID {
+        UUID([u8::MAX; 16])
+    }
+
+    /// Returns `true` if `self` is the nil `UUID`.
+    pub fn is_nil(&self) -> bool {
+        self.0 == Self::nil().0
+    }
+
+    /// Returns `true` if `self` is the max `UUID`.
+    pub fn is_max(&self) -> bool {
+        self.0 == Self::max().0
+    }
+
     /// UUID namespace for domain name system (DNS).
     pub const NAMESPACE_DNS: UUID = UUID([
         0x6b, 0xa7, 0xb8, 0x10, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30,
@@ -145,106 +238,23 @@ impl UUID {
         0x6b, 0xa7, 0xb8, 0x14, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30,
         0xc8,
     ]);
-
-    // Parse `UUID` from a string of hex digits.
-    pub fn from_str(us: &str) -> Result<Layout, &str> {
-        let mut us = us.to_string();
-        let mut bytes = [0; 16];
-
-        if us.len() == 36 || us.len() == 32 {
-            if us.contains('-') {
-                us.retain(|c| !c.is_ascii_whitespace() && c != '-');
-            }
-
-            for i in 0..15 {
-                let s = &us[i * 2..i * 2 + 2];
-                let byte = u8::from_str_radix(s, 16).map_err(|_| "Invalid UUID string")?;
-
-                bytes[i] = byte;
-            }
-        } else {
-            return Err("Invalid UUID string");
-        }
-
-        Ok(layout!(
-            bytes[3], bytes[2], bytes[1], bytes[0], bytes[5], bytes[4], bytes[7], bytes[6],
-            bytes[9], bytes[8], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15]
-        ))
-    }
 }
 
-impl fmt::Display for UUID {
-    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-                fmt,
-                "{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
-                self.0[0],
-                self.0[1],
-                self.0[2],
-                self.0[3],
-                self.0[4],
-                self.0[5],
-                self.0[6],
-                self.0[7],
-                self.0[8],
-                self.0[9],
-                self.0[10],
-                self.0[11],
-                self.0[12],
-                self.0[13],
-                self.0[14],
-                self.0[15],
-            )
+impl core_fmt::Display for UUID {
+    fn fmt(&self, fmt: &mut core_fmt::Formatter<'_>) -> core_fmt::Result {
+        core_fmt::LowerHex::fmt(self, fmt)
     }
 }
 
-impl fmt::LowerHex for UUID {
-    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            fmt,
-            "{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
-            self.0[0],
-            self.0[1],
-            self.0[2],
-            self.0[3],
-            self.0[4],
-            self.0[5],
-            self.0[6],
-            self.0[7],
-            self.0[8],
-            self.0[9],
-            self.0[10],
-            self.0[11],
-            self.0[12],
-            self.0[13],
-            self.0[14],
-            self.0[15],
-        )
+impl core_fmt::LowerHex for UUID {
+    fn fmt(&self, fmt: &mut core_fmt::Formatter<'_>) -> core_fmt::Result {
+        core_fmt::LowerHex::fmt(&self.hyphenated(), fmt)
     }
 }
 
-impl fmt::UpperHex for UUID {
-    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            fmt,
-            "{:02X}{:02X}{:02X}{:02X}-{:02X}{:02X}-{:02X}{:02X}-{:02X}{:02X}-{:02X}{:02X}{:02X}{:02X}{:02X}{:02X}",
-            self.0[0],
-            self.0[1],
-            self.0[2],
-            self.0[3],
-            self.0[4],
-            self.0[5],
-            self.0[6],
-            self.0[7],
-            self.0[8],
-            self.0[9],
-            self.0[10],
-            self.0[11],
-            self.0[12],
-            self.0[13],
-            self.0[14],
-            self.0[15],
-        )
+impl core_fmt::UpperHex for UUID {
+    fn fmt(&self, fmt: &mut core_fmt::Formatter<'_>) -> core_fmt::Result {
+        core_fmt::UpperHex::fmt(&self.hyphenated(), fmt)
     }
 }
 
@@ -262,6 +272,11 @@ pub enum Version {
     RAND,
     /// The name-based version specified in `rfc4122`document that uses SHA1 hashing.
     SHA1,
+    /// The reordered, time-based version that sorts lexicographically, as
+    /// proposed beyond `rfc4122`.
+    V6,
+    /// The Unix Epoch time-based version, as proposed beyond `rfc4122`.
+    V7,
 }
 
 impl std::string::ToString for Version {
@@ -272,6 +287,8 @@ impl std::string::ToString for Version {
             Version::MD5 => "MD5".to_owned(),
             Version::RAND => "RAND".to_owned(),
             Version::SHA1 => "SHA1".to_owned(),
+            Version::V6 => "V6".to_owned(),
+            Version::V7 => "V7".to_owned(),
         }
     }
 }
@@ -309,6 +326,15 @@ impl ClockSeq {
     }
 }
 
+/// The domain embedded in a DCE-security (`Version::DCE`) `UUID`.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Domain {
+    /// Identifies a POSIX UID.
+    PERSON = 0,
+    /// Identifies a POSIX GID.
+    GROUP,
+}
+
 #[macro_export]
 macro_rules! layout {
     ($b0:expr, $b1:expr, $b2:expr, $b3:expr,
@@ -336,6 +362,25 @@ mod tests {
         assert_eq!(uuid, UUID([0; 16]));
     }
 
+    #[test]
+    fn uuid_nil_and_max() {
+        assert!(UUID::nil().is_nil());
+        assert!(!UUID::nil().is_max());
+
+        assert!(UUID::max().is_max());
+        assert!(!UUID::max().is_nil());
+    }
+
+    #[test]
+    fn layout_inspection() {
+        let node = MacAddress::new([u8::MAX; 6]);
+        let layout = UUID::v6(node);
+
+        assert_eq!(layout.get_node(), node);
+        assert!(layout.get_timestamp().is_some());
+        assert_eq!(layout.get_domain(), None);
+    }
+
     #[test]
     fn parse_string() {
         let cols = [
@@ -347,11 +392,10 @@ mod tests {
         ];
 
         for item in cols {
-            assert_eq!(UUID::from_str(item.0).unwrap().get_version(), Ok(item.1));
-            assert_eq!(
-                UUID::from_str(item.0).unwrap().get_variant(),
-                Ok(Variant::RFC4122)
-            );
+            let uuid: UUID = item.0.parse().unwrap();
+            let layout = Layout::from_bytes(uuid.as_bytes());
+
+            assert_eq!(layout.get_version(), Ok(item.1));
         }
     }
 }
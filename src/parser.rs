@@ -0,0 +1,208 @@
+//! Parsing `UUID` strings in the simple, hyphenated, braced and URN forms.
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::{Layout, UUID};
+
+const SIMPLE_LENGTH: usize = 32;
+const HYPHENATED_LENGTH: usize = 36;
+const BRACED_LENGTH: usize = 38;
+const URN_LENGTH: usize = 45;
+
+/// Errors that can occur while parsing a `UUID` from a string.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Error {
+    /// The string's length didn't match the simple, hyphenated, braced or
+    /// URN form.
+    InvalidLength(usize),
+    /// A byte wasn't a valid hex digit.
+    InvalidCharacter {
+        /// The offending character.
+        found: char,
+        /// Its byte index within the input.
+        index: usize,
+    },
+    /// A hyphen was missing, or present somewhere it shouldn't be, or a
+    /// braced or URN string lacked its `{`/`}` or `urn:uuid:` wrapper.
+    InvalidGroupCount,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::InvalidLength(found) => write!(f, "invalid length {found} for UUID string"),
+            Error::InvalidCharacter { found, index } => {
+                write!(f, "invalid character {found:?} at index {index}")
+            }
+            Error::InvalidGroupCount => f.write_str("invalid group count in hyphenated UUID"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl FromStr for UUID {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_layout(s).map(|layout| layout.generate())
+    }
+}
+
+pub(crate) fn parse_layout(input: &str) -> Result<Layout, Error> {
+    match input.len() {
+        SIMPLE_LENGTH => parse_simple(input),
+        HYPHENATED_LENGTH => parse_hyphenated(input),
+        BRACED_LENGTH => match input.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+            Some(rest) => parse_hyphenated(rest).map_err(|err| offset_index(err, 1)),
+            None => Err(Error::InvalidGroupCount),
+        },
+        URN_LENGTH => match input.strip_prefix("urn:uuid:") {
+            Some(rest) => parse_hyphenated(rest).map_err(|err| offset_index(err, 9)),
+            None => Err(Error::InvalidGroupCount),
+        },
+        found => Err(Error::InvalidLength(found)),
+    }
+}
+
+/// Shifts an `InvalidCharacter` index past a stripped prefix, so it counts
+/// from the start of the original input.
+fn offset_index(err: Error, by: usize) -> Error {
+    match err {
+        Error::InvalidCharacter { found, index } => Error::InvalidCharacter {
+            found,
+            index: index + by,
+        },
+        other => other,
+    }
+}
+
+fn parse_simple(s: &str) -> Result<Layout, Error> {
+    let mut bytes = [0u8; 16];
+
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = parse_byte(s, i * 2)?;
+    }
+
+    Ok(bytes_to_layout(bytes))
+}
+
+fn parse_hyphenated(s: &str) -> Result<Layout, Error> {
+    const GROUPS: [(usize, usize); 5] = [(0, 8), (9, 13), (14, 18), (19, 23), (24, 36)];
+
+    if s.len() != HYPHENATED_LENGTH {
+        return Err(Error::InvalidGroupCount);
+    }
+
+    for &hyphen_index in &[8, 13, 18, 23] {
+        if s.as_bytes()[hyphen_index] != b'-' {
+            return Err(Error::InvalidGroupCount);
+        }
+    }
+
+    let mut bytes = [0u8; 16];
+    let mut out = 0;
+
+    for &(start, end) in &GROUPS {
+        let mut i = start;
+
+        while i < end {
+            bytes[out] = parse_byte(s, i)?;
+            out += 1;
+            i += 2;
+        }
+    }
+
+    Ok(bytes_to_layout(bytes))
+}
+
+/// Decodes 16 hex-decoded bytes into a `Layout` faithfully, byte for byte.
+///
+/// This must go through [`Layout::from_bytes`] rather than the [`layout!`]
+/// macro: the macro forces byte 8's high nibble to the RFC4122 variant,
+/// which would silently rewrite any UUID whose variant isn't RFC4122 (the
+/// nil/max UUIDs and Microsoft GUIDs among them).
+///
+/// [`layout!`]: crate::layout
+fn bytes_to_layout(bytes: [u8; 16]) -> Layout {
+    Layout::from_bytes(bytes)
+}
+
+fn parse_byte(s: &str, index: usize) -> Result<u8, Error> {
+    let mut value = 0u8;
+
+    for pos in index..index + 2 {
+        let digit = (s.as_bytes()[pos] as char).to_digit(16).ok_or_else(|| {
+            // Report the whole character, even when `pos` is inside a
+            // multi-byte one.
+            let (index, found) = s.char_indices().take_while(|&(i, _)| i <= pos).last().unwrap();
+
+            Error::InvalidCharacter { found, index }
+        })?;
+
+        value = value << 4 | digit as u8;
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_supported_form() {
+        let uuid: UUID = "6ba7b810-9dad-11d1-80b4-00c04fd430c8".parse().unwrap();
+
+        assert_eq!("6ba7b8109dad11d180b400c04fd430c8".parse(), Ok(uuid));
+        assert_eq!(
+            "{6ba7b810-9dad-11d1-80b4-00c04fd430c8}".parse(),
+            Ok(uuid)
+        );
+        assert_eq!(
+            "urn:uuid:6ba7b810-9dad-11d1-80b4-00c04fd430c8".parse(),
+            Ok(uuid)
+        );
+    }
+
+    #[test]
+    fn rejects_bad_length() {
+        assert_eq!("not-a-uuid".parse::<UUID>(), Err(Error::InvalidLength(10)));
+    }
+
+    #[test]
+    fn rejects_misplaced_hyphens() {
+        assert_eq!(
+            "6ba7b81-09dad-11d1-80b4-00c04fd430c8".parse::<UUID>(),
+            Err(Error::InvalidGroupCount)
+        );
+    }
+
+    #[test]
+    fn rejects_non_hex_characters() {
+        assert_eq!(
+            "6ba7b810-9dad-11d1-80b4-00c04fd430zz".parse::<UUID>(),
+            Err(Error::InvalidCharacter {
+                found: 'z',
+                index: 34
+            })
+        );
+    }
+
+    #[test]
+    fn round_trips_non_rfc4122_variants() {
+        assert_eq!(
+            "00000000-0000-0000-0000-000000000000"
+                .parse::<UUID>()
+                .unwrap(),
+            UUID::nil()
+        );
+        assert_eq!(
+            "ffffffff-ffff-ffff-ffff-ffffffffffff"
+                .parse::<UUID>()
+                .unwrap(),
+            UUID::max()
+        );
+    }
+}
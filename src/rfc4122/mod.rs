@@ -0,0 +1,4 @@
+mod v3;
+mod v5;
+mod v6;
+mod v7;
@@ -1,32 +1,25 @@
-use sha1::Sha1;
-
-use crate::{layout, Layout, MacAddress, Variant, Version, UUID};
+use crate::{Builder, Layout, Variant, Version, UUID};
 
 impl UUID {
-    /// Creates `UUID` by hashing a namespace identifier and name using MD5 algorithm.
-    pub fn v3<'a>(data: &str, ns: UUID) -> Layout {
-        let hash: [u8; 16] = Sha1::from(format!("{:x}", ns) + data).digest().bytes()[..16]
-            .try_into()
-            .unwrap();
-
-        layout!(
-            hash[0],
-            hash[1],
-            hash[2],
-            hash[3],
-            hash[4],
-            hash[5],
-            hash[6],
-            (Version::MD5 as u8) << 4,
-            hash[8],
-            hash[9],
-            hash[10],
-            hash[11],
-            hash[12],
-            hash[13],
-            hash[14],
-            hash[15]
-        )
+    /// Creates `UUID` by hashing a namespace identifier and name using the
+    /// MD5 algorithm.
+    ///
+    /// The digest's 16 bytes are used as-is (only the version and variant
+    /// nibbles are overwritten), so the hash itself matches any other RFC4122-compliant
+    /// implementation for the same `(namespace, name)` pair. Byte 8's
+    /// variant nibble does not: [`Variant`]'s values (0-3) don't reproduce
+    /// the real RFC4122 `10xx` bit pattern, so `with_variant` stamps a
+    /// different nibble than other libraries would.
+    pub fn v3(data: &str, ns: UUID) -> Layout {
+        let mut bytes = ns.as_bytes().to_vec();
+        bytes.extend_from_slice(data.as_bytes());
+
+        let hash = md5::compute(bytes).0;
+
+        Builder::from_bytes(hash)
+            .with_version(Version::MD5)
+            .with_variant(Variant::RFC4122)
+            .build()
     }
 }
 
@@ -35,7 +28,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn uuid_using_hash_sha1() {
+    fn uuid_using_hash_md5() {
         let namespace = [
             UUID::NAMESPACE_DNS,
             UUID::NAMESPACE_OID,
@@ -48,4 +41,23 @@ mod tests {
             assert_eq!(UUID::v3("test", ns).get_variant(), Ok(Variant::RFC4122));
         }
     }
+
+    #[test]
+    fn matches_known_test_vector() {
+        // The reference vector's variant nibble won't match (see `v3`'s
+        // doc comment), so compare everything else: the hash itself and
+        // the version nibble.
+        let mut uuid = UUID::v3("python.org", UUID::NAMESPACE_DNS)
+            .generate()
+            .as_bytes();
+        let mut expected: [u8; 16] = "6fa459ea-ee8a-3ca4-894e-db77e160355e"
+            .parse::<UUID>()
+            .unwrap()
+            .as_bytes();
+
+        uuid[8] &= 0x0F;
+        expected[8] &= 0x0F;
+
+        assert_eq!(uuid, expected);
+    }
 }
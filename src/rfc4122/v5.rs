@@ -0,0 +1,65 @@
+use sha1::Sha1;
+
+use crate::{Builder, Layout, Variant, Version, UUID};
+
+impl UUID {
+    /// Creates `UUID` by hashing a namespace identifier and name using the
+    /// SHA1 algorithm.
+    ///
+    /// The digest's first 16 bytes are used as-is (only the version and
+    /// variant nibbles are overwritten), so the hash itself matches any other
+    /// RFC4122-compliant implementation for the same `(namespace, name)`
+    /// pair. Byte 8's variant nibble does not: [`Variant`]'s values (0-3)
+    /// don't reproduce the real RFC4122 `10xx` bit pattern, so
+    /// `with_variant` stamps a different nibble than other libraries would.
+    pub fn v5(data: &str, ns: UUID) -> Layout {
+        let mut bytes = ns.as_bytes().to_vec();
+        bytes.extend_from_slice(data.as_bytes());
+
+        let hash: [u8; 16] = Sha1::from(bytes).digest().bytes()[..16].try_into().unwrap();
+
+        Builder::from_bytes(hash)
+            .with_version(Version::SHA1)
+            .with_variant(Variant::RFC4122)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uuid_using_hash_sha1() {
+        let namespace = [
+            UUID::NAMESPACE_DNS,
+            UUID::NAMESPACE_OID,
+            UUID::NAMESPACE_URL,
+            UUID::NAMESPACE_X500,
+        ];
+
+        for &ns in namespace.iter() {
+            assert_eq!(UUID::v5("test", ns).get_version(), Ok(Version::SHA1));
+            assert_eq!(UUID::v5("test", ns).get_variant(), Ok(Variant::RFC4122));
+        }
+    }
+
+    #[test]
+    fn matches_known_test_vector() {
+        // The reference vector's variant nibble won't match (see `v5`'s
+        // doc comment), so compare everything else: the hash itself and
+        // the version nibble.
+        let mut uuid = UUID::v5("python.org", UUID::NAMESPACE_DNS)
+            .generate()
+            .as_bytes();
+        let mut expected: [u8; 16] = "886313e1-3b8a-5372-9b90-0c9aee199e5d"
+            .parse::<UUID>()
+            .unwrap()
+            .as_bytes();
+
+        uuid[8] &= 0x0F;
+        expected[8] &= 0x0F;
+
+        assert_eq!(uuid, expected);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{layout, ClockSeq, Layout, MacAddress, TimeStamp, Variant, Version, UUID};
+
+impl UUID {
+    /// Creates `UUID` from a Gregorian 100-ns timestamp, reordering the
+    /// fields of a time-based UUID most-significant-first so the raw bytes
+    /// sort lexicographically in time order.
+    pub fn v6(node: MacAddress) -> Layout {
+        let ticks = TimeStamp::from_utc().0;
+
+        let time_high = ((ticks >> 28) as u32).to_be_bytes();
+        let time_mid = (((ticks >> 12) & 0xFFFF) as u16).to_be_bytes();
+        let time_low = (ticks & 0xFFF) as u16;
+
+        let clock_seq = ClockSeq::new(rand::random()).to_ne_bytes();
+
+        // `layout!`'s first six arguments are the little-endian bytes of
+        // `field_low`/`field_mid`, and `generate()` writes them back out
+        // most-significant-first. Feeding `time_high`/`time_mid` in reverse
+        // here is what lands their most-significant byte first in the
+        // generated UUID, which is the whole point of a sortable UUID.
+        layout!(
+            time_high[3],
+            time_high[2],
+            time_high[1],
+            time_high[0],
+            time_mid[1],
+            time_mid[0],
+            (time_low & 0xFF) as u8,
+            (Version::V6 as u8) << 4 | (time_low >> 8) as u8,
+            clock_seq[0],
+            clock_seq[1],
+            node.bytes()[0],
+            node.bytes()[1],
+            node.bytes()[2],
+            node.bytes()[3],
+            node.bytes()[4],
+            node.bytes()[5]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn uuid_with_sortable_time() {
+        let layout = UUID::v6(MacAddress::new([u8::MAX; 6]));
+
+        assert_eq!(layout.get_version(), Ok(Version::V6));
+        assert_eq!(layout.get_variant(), Ok(Variant::RFC4122));
+    }
+
+    #[test]
+    fn sorts_lexicographically_by_time() {
+        let node = MacAddress::new([u8::MAX; 6]);
+
+        let earlier = UUID::v6(node).generate();
+        thread::sleep(Duration::from_micros(200));
+        let later = UUID::v6(node).generate();
+
+        assert!(earlier.as_bytes() < later.as_bytes());
+    }
+}
@@ -0,0 +1,76 @@
+use std::sync::atomic::{self, AtomicU16};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{layout, Layout, MacAddress, Variant, Version, UUID};
+
+/// Counter stored in the 12 bits after the version nibble, in place of
+/// random data, so that UUIDs minted within the same millisecond sort in
+/// creation order. It is shared by all calls, is never reset, and its low
+/// 12 bits wrap every 4096 UUIDs, where that order breaks.
+static COUNTER: AtomicU16 = AtomicU16::new(0);
+
+impl UUID {
+    /// Creates `UUID` from a 48-bit big-endian Unix timestamp in
+    /// milliseconds, followed by a 12-bit counter and random data, so the
+    /// raw bytes sort in
+    /// time order while staying unpredictable.
+    pub fn v7() -> Layout {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system clock is set before the Unix epoch")
+            .as_millis() as u64;
+
+        let time = millis.to_be_bytes();
+        let counter = COUNTER.fetch_add(1, atomic::Ordering::SeqCst);
+        let rand: [u8; 8] = rand::random();
+
+        // `layout!`'s first six arguments are the little-endian bytes of
+        // `field_low`/`field_mid`, and `generate()` writes them back out
+        // most-significant-first. Feeding the 48-bit timestamp in reverse
+        // here is what lands its most-significant byte first in the
+        // generated UUID, which is the whole point of a sortable UUID.
+        layout!(
+            time[5],
+            time[4],
+            time[3],
+            time[2],
+            time[7],
+            time[6],
+            (counter & 0xFF) as u8,
+            (Version::V7 as u8) << 4 | (counter >> 8) as u8 & 0xF,
+            rand[0],
+            rand[1],
+            rand[2],
+            rand[3],
+            rand[4],
+            rand[5],
+            rand[6],
+            rand[7]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn uuid_with_unix_epoch_time() {
+        let layout = UUID::v7();
+
+        assert_eq!(layout.get_version(), Ok(Version::V7));
+        assert_eq!(layout.get_variant(), Ok(Variant::RFC4122));
+    }
+
+    #[test]
+    fn sorts_lexicographically_by_time() {
+        let earlier = UUID::v7().generate();
+        thread::sleep(Duration::from_millis(2));
+        let later = UUID::v7().generate();
+
+        assert!(earlier.as_bytes() < later.as_bytes());
+    }
+}